@@ -0,0 +1,136 @@
+//! Lenient deserializers for fields Printavo sometimes sends as JSON strings.
+//!
+//! Monetary and id fields are documented as numbers, but individual endpoints
+//! occasionally quote them (e.g. `"100.00"` instead of `100.00`). These
+//! helpers accept either form instead of failing the whole payload.
+
+use std::fmt;
+
+use serde::de::{self, Visitor};
+use serde::Deserializer;
+
+pub(crate) fn deserialize_f32<'de, D>(deserializer: D) -> Result<f32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct F32Visitor;
+
+    impl<'de> Visitor<'de> for F32Visitor {
+        type Value = f32;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a number or a string containing a number")
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+            Ok(value as f32)
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+            Ok(value as f32)
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            Ok(value as f32)
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            value.parse().map_err(de::Error::custom)
+        }
+    }
+
+    deserializer.deserialize_any(F32Visitor)
+}
+
+pub(crate) fn deserialize_u32<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct U32Visitor;
+
+    impl<'de> Visitor<'de> for U32Visitor {
+        type Value = u32;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a number or a string containing a number")
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+            if value.fract() != 0.0 || value < 0.0 || value > u32::MAX as f64 {
+                return Err(de::Error::custom(format!(
+                    "invalid value: {value}, expected a u32"
+                )));
+            }
+            Ok(value as u32)
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+            value.try_into().map_err(de::Error::custom)
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            value.try_into().map_err(de::Error::custom)
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            value.parse().map_err(de::Error::custom)
+        }
+    }
+
+    deserializer.deserialize_any(U32Visitor)
+}
+
+#[cfg(test)]
+mod tests {
+    #[derive(serde::Deserialize)]
+    struct F32Wrapper(#[serde(deserialize_with = "super::deserialize_f32")] f32);
+
+    #[derive(serde::Deserialize)]
+    struct U32Wrapper(#[serde(deserialize_with = "super::deserialize_u32")] u32);
+
+    #[test]
+    fn f32_accepts_numeric_form() {
+        assert_eq!(serde_json::from_str::<F32Wrapper>("100.5").unwrap().0, 100.5);
+    }
+
+    #[test]
+    fn f32_accepts_quoted_string_form() {
+        assert_eq!(
+            serde_json::from_str::<F32Wrapper>("\"100.00\"").unwrap().0,
+            100.0
+        );
+    }
+
+    #[test]
+    fn u32_accepts_numeric_form() {
+        assert_eq!(serde_json::from_str::<U32Wrapper>("1000").unwrap().0, 1000);
+    }
+
+    #[test]
+    fn u32_accepts_quoted_string_form() {
+        assert_eq!(
+            serde_json::from_str::<U32Wrapper>("\"1000\"").unwrap().0,
+            1000
+        );
+    }
+
+    #[test]
+    fn u32_rejects_negative_int() {
+        assert!(serde_json::from_str::<U32Wrapper>("-5").is_err());
+    }
+
+    #[test]
+    fn u32_rejects_negative_float() {
+        assert!(serde_json::from_str::<U32Wrapper>("-1.0").is_err());
+    }
+
+    #[test]
+    fn u32_rejects_fractional_float() {
+        assert!(serde_json::from_str::<U32Wrapper>("5.5").is_err());
+    }
+
+    #[test]
+    fn u32_rejects_overflowing_float() {
+        assert!(serde_json::from_str::<U32Wrapper>("1e20").is_err());
+    }
+}
@@ -17,4 +17,47 @@ pub enum Error {
         #[from]
         source: url::ParseError,
     },
+    /// Printavo responded with a non-2xx status, or an inbound webhook failed
+    /// signature verification (reported as `401 Unauthorized`).
+    #[error("API error ({status}): {message}")]
+    Api {
+        status: reqwest::StatusCode,
+        message: String,
+        details: Vec<ErrorDetail>,
+    },
+}
+
+/// A single field-level issue reported by one of Printavo's validation errors.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ErrorDetail {
+    pub field: String,
+    pub reason: String,
+}
+
+/// Printavo's error response body, as best we can guess at its shape.
+#[derive(Debug, Default, serde::Deserialize)]
+struct ApiErrorBody {
+    #[serde(default)]
+    message: Option<String>,
+    #[serde(default)]
+    errors: Vec<ErrorDetail>,
+}
+
+impl Error {
+    /// Build an [`Error::Api`] from a non-2xx response, falling back to the
+    /// raw response body when it isn't the JSON shape we expect.
+    pub(crate) async fn from_response(response: reqwest::Response) -> Self {
+        let status = response.status();
+        let text = match response.text().await {
+            Ok(text) => text,
+            Err(source) => return Error::Http { source },
+        };
+
+        let body: ApiErrorBody = serde_json::from_str(&text).unwrap_or_default();
+        Error::Api {
+            status,
+            message: body.message.unwrap_or(text),
+            details: body.errors,
+        }
+    }
 }
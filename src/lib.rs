@@ -1,16 +1,24 @@
 const MAX_RETRIES: u32 = 3;
+const DEFAULT_RETRY_BASE_DELAY: std::time::Duration = std::time::Duration::from_millis(200);
+const DEFAULT_RETRY_MAX_DELAY: std::time::Duration = std::time::Duration::from_secs(30);
 
 pub mod auth;
+mod deserialize;
 pub mod error;
 pub mod from_response;
 pub mod orders;
 pub mod page;
 pub mod params;
+#[cfg(test)]
+mod test_support;
+pub mod webhooks;
 
+use rand::Rng;
 use reqwest::header::HeaderName;
 use reqwest::StatusCode;
 use secrecy::{ExposeSecret, SecretString};
 use serde::Serialize;
+use std::time::Duration;
 use url::Url;
 
 use auth::Auth;
@@ -57,6 +65,11 @@ pub struct Printavo {
     auth_state: AuthState,
     pub base_url: Url,
     version: Version,
+    webhook_signing_secret: Option<String>,
+    retry_base_delay: Duration,
+    retry_max_delay: Duration,
+    max_retries: u32,
+    retry_posts: bool,
 }
 
 impl Printavo {
@@ -74,6 +87,10 @@ impl Printavo {
         orders::OrdersHandler::new(self)
     }
 
+    pub fn webhooks(&self) -> webhooks::WebhooksHandler<'_> {
+        webhooks::WebhooksHandler::new(self)
+    }
+
     /// Send a `GET` request to `route` with optional query parameters, returning
     /// the body of the response.
     pub async fn get<R, A, P>(&self, route: A, parameters: Option<&P>) -> Result<R>
@@ -151,11 +168,24 @@ impl Printavo {
     }
 
     /// Execute the given `request` using printavo's Client.
+    ///
+    /// Besides the `401` re-auth retry, `GET`s (and `POST`s when
+    /// [`PrintavoBuilder::retry_posts`] is enabled) are retried on `429` and
+    /// `503` with exponential backoff and jitter, honoring a `Retry-After`
+    /// header when Printavo sends one.
     pub async fn execute(&self, mut request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
-        let mut retries = 0;
+        let mut auth_retries = 0;
+        let mut transient_retries = 0;
         loop {
             // Saved request that we can retry later if necessary
-            let mut retry_request = None;
+            let retry_request = request.try_clone();
+
+            let is_get = retry_request
+                .as_ref()
+                .and_then(|r| r.try_clone())
+                .and_then(|r| r.build().ok())
+                .map(|built| built.method() == reqwest::Method::GET)
+                .unwrap_or(false);
 
             match self.auth_state {
                 AuthState::None => (),
@@ -163,7 +193,6 @@ impl Printavo {
                     ref email,
                     ref token,
                 } => {
-                    retry_request = Some(request.try_clone().unwrap());
                     request = request.query(&[("email", email), ("token", token)]);
                 }
             }
@@ -173,16 +202,181 @@ impl Printavo {
                 Ok(v) => Some(v.status()),
                 Err(e) => e.status(),
             };
+
             if let Some(StatusCode::UNAUTHORIZED) = status {
-                if let Some(retry) = retry_request {
-                    if retries < MAX_RETRIES {
-                        retries += 1;
+                if auth_retries < MAX_RETRIES {
+                    if let Some(retry) = retry_request {
+                        auth_retries += 1;
                         request = retry;
                         continue;
                     }
                 }
             }
-            return Ok(result?);
+
+            let transient = matches!(
+                status,
+                Some(StatusCode::TOO_MANY_REQUESTS) | Some(StatusCode::SERVICE_UNAVAILABLE)
+            );
+            if transient
+                && (is_get || self.retry_posts)
+                && transient_retries < self.max_retries
+            {
+                if let Some(retry) = retry_request {
+                    let delay = result
+                        .as_ref()
+                        .ok()
+                        .and_then(|response| retry_after_delay(response.headers()))
+                        .unwrap_or_else(|| {
+                            backoff_delay(self.retry_base_delay, self.retry_max_delay, transient_retries)
+                        });
+
+                    transient_retries += 1;
+                    tokio::time::sleep(delay).await;
+                    request = retry;
+                    continue;
+                }
+            }
+
+            let response = result?;
+            if !response.status().is_success() {
+                return Err(error::Error::from_response(response).await);
+            }
+            return Ok(response);
+        }
+    }
+}
+
+/// Parse a `Retry-After` header (either delay-seconds or an HTTP-date) into
+/// the [`Duration`] to wait before retrying.
+fn retry_after_delay(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let value = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let when = httpdate::parse_http_date(value).ok()?;
+    Some(when.duration_since(std::time::SystemTime::now()).unwrap_or(Duration::ZERO))
+}
+
+/// Exponential backoff with jitter, capped at `max`.
+fn backoff_delay(base: Duration, max: Duration, attempt: u32) -> Duration {
+    let exponential = base.saturating_mul(2u32.saturating_pow(attempt));
+    let capped = exponential.min(max);
+    let jitter = Duration::from_millis(rand::thread_rng().gen_range(0..=capped.as_millis() as u64));
+    capped / 2 + jitter / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers_with_retry_after(value: &str) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(reqwest::header::RETRY_AFTER, value.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn retry_after_delay_parses_bare_seconds() {
+        let headers = headers_with_retry_after("120");
+        assert_eq!(retry_after_delay(&headers), Some(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn retry_after_delay_is_zero_for_a_past_http_date() {
+        let past = std::time::SystemTime::now() - Duration::from_secs(3600);
+        let headers = headers_with_retry_after(&httpdate::fmt_http_date(past));
+        assert_eq!(retry_after_delay(&headers), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn retry_after_delay_waits_for_a_future_http_date() {
+        let future = std::time::SystemTime::now() + Duration::from_secs(60);
+        let headers = headers_with_retry_after(&httpdate::fmt_http_date(future));
+        let delay = retry_after_delay(&headers).expect("a duration");
+        // httpdate truncates to whole seconds, so allow a little slack.
+        assert!(delay >= Duration::from_secs(58) && delay <= Duration::from_secs(61));
+    }
+
+    #[test]
+    fn retry_after_delay_is_none_without_the_header() {
+        let headers = reqwest::header::HeaderMap::new();
+        assert_eq!(retry_after_delay(&headers), None);
+    }
+
+    #[test]
+    fn backoff_delay_grows_with_attempt_and_is_capped() {
+        let base = Duration::from_millis(100);
+        let max = Duration::from_secs(1);
+
+        let first = backoff_delay(base, max, 0);
+        assert!(first >= base / 2 && first <= base);
+
+        // A large attempt count would overflow the exponential backoff well
+        // past `max`; the result must still be capped.
+        let capped = backoff_delay(base, max, 20);
+        assert!(capped >= max / 2 && capped <= max);
+    }
+
+    #[tokio::test]
+    async fn execute_surfaces_structured_api_errors() {
+        let body = r#"{"message":"Validation failed","errors":[{"field":"email","reason":"is invalid"}]}"#;
+        let (base_url, _server) = test_support::spawn_responder(vec![test_support::json_response(
+            422,
+            "Unprocessable Entity",
+            body,
+        )])
+        .await;
+        let printavo = Printavo::builder().base_url(base_url).unwrap().build().unwrap();
+
+        let err = printavo
+            .get::<serde_json::Value, _, ()>("anything", None)
+            .await
+            .unwrap_err();
+
+        match err {
+            error::Error::Api {
+                status,
+                message,
+                details,
+            } => {
+                assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+                assert_eq!(message, "Validation failed");
+                assert_eq!(details.len(), 1);
+                assert_eq!(details[0].field, "email");
+                assert_eq!(details[0].reason, "is invalid");
+            }
+            other => panic!("expected Error::Api, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_falls_back_to_raw_text_for_non_json_error_bodies() {
+        let (base_url, _server) = test_support::spawn_responder(vec![test_support::text_response(
+            500,
+            "Internal Server Error",
+            "something broke",
+        )])
+        .await;
+        let printavo = Printavo::builder().base_url(base_url).unwrap().build().unwrap();
+
+        let err = printavo
+            .get::<serde_json::Value, _, ()>("anything", None)
+            .await
+            .unwrap_err();
+
+        match err {
+            error::Error::Api {
+                status,
+                message,
+                details,
+            } => {
+                assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+                assert_eq!(message, "something broke");
+                assert!(details.is_empty());
+            }
+            other => panic!("expected Error::Api, got {other:?}"),
         }
     }
 }
@@ -197,16 +391,41 @@ impl Default for Printavo {
             auth_state: AuthState::None,
             base_url: Url::parse(PRINTAVO_BASE_URL).unwrap(),
             version: Version::V1,
+            webhook_signing_secret: None,
+            retry_base_delay: DEFAULT_RETRY_BASE_DELAY,
+            retry_max_delay: DEFAULT_RETRY_MAX_DELAY,
+            max_retries: MAX_RETRIES,
+            retry_posts: false,
         }
     }
 }
 
-#[derive(Default)]
 pub struct PrintavoBuilder {
     auth: Auth,
     extra_headers: Vec<(HeaderName, String)>,
     base_url: Option<Url>,
     version: Version,
+    webhook_signing_secret: Option<SecretString>,
+    retry_base_delay: Duration,
+    retry_max_delay: Duration,
+    max_retries: u32,
+    retry_posts: bool,
+}
+
+impl Default for PrintavoBuilder {
+    fn default() -> Self {
+        Self {
+            auth: Auth::default(),
+            extra_headers: Vec::new(),
+            base_url: None,
+            version: Version::default(),
+            webhook_signing_secret: None,
+            retry_base_delay: DEFAULT_RETRY_BASE_DELAY,
+            retry_max_delay: DEFAULT_RETRY_MAX_DELAY,
+            max_retries: MAX_RETRIES,
+            retry_posts: false,
+        }
+    }
 }
 
 impl PrintavoBuilder {
@@ -240,6 +459,45 @@ impl PrintavoBuilder {
         self
     }
 
+    /// Configure the signing secret used to verify inbound webhook payloads.
+    ///
+    /// See [`webhooks::WebhooksHandler::parse`].
+    pub fn webhook_signing_secret(mut self, secret: String) -> Self {
+        self.webhook_signing_secret = Some(SecretString::new(secret));
+        self
+    }
+
+    /// The base delay used for exponential backoff when retrying `429`/`503`
+    /// responses (default: 200ms). Ignored when Printavo sends a
+    /// `Retry-After` header, which is honored exactly.
+    pub fn retry_base_delay(mut self, retry_base_delay: Duration) -> Self {
+        self.retry_base_delay = retry_base_delay;
+        self
+    }
+
+    /// The maximum backoff delay between retries of `429`/`503` responses
+    /// (default: 30s). A `Retry-After` header is honored as sent and is not
+    /// capped by this.
+    pub fn retry_max_delay(mut self, retry_max_delay: Duration) -> Self {
+        self.retry_max_delay = retry_max_delay;
+        self
+    }
+
+    /// How many times to retry a `429`/`503` response before giving up
+    /// (default: 3).
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Whether `POST` requests may also be retried on `429`/`503` (default:
+    /// `false`, since POSTs aren't guaranteed idempotent). `GET`s are always
+    /// eligible.
+    pub fn retry_posts(mut self, retry_posts: bool) -> Self {
+        self.retry_posts = retry_posts;
+        self
+    }
+
     pub fn build(self) -> Result<Printavo> {
         let mut headers = reqwest::header::HeaderMap::new();
 
@@ -267,6 +525,13 @@ impl PrintavoBuilder {
                 .base_url
                 .unwrap_or_else(|| Url::parse(PRINTAVO_BASE_URL).unwrap()),
             version: self.version,
+            webhook_signing_secret: self
+                .webhook_signing_secret
+                .map(|secret| secret.expose_secret().to_string()),
+            retry_base_delay: self.retry_base_delay,
+            retry_max_delay: self.retry_max_delay,
+            max_retries: self.max_retries,
+            retry_posts: self.retry_posts,
         })
     }
 }
@@ -1,30 +1,35 @@
 use crate::params;
 use crate::Printavo;
 
-#[derive(serde::Deserialize)]
+#[derive(Debug, serde::Deserialize)]
 pub struct Order {
+    #[serde(deserialize_with = "crate::deserialize::deserialize_u32")]
     pub id: u32,
+    #[serde(deserialize_with = "crate::deserialize::deserialize_f32")]
     pub order_total: f32,
 }
 
 /// Handler for Printavo's Orders API
 ///
 /// Created with [`Printavo::orders`].
-pub struct OrdersHandler<'p> {
-    printavo: &'p Printavo,
+#[derive(Clone)]
+pub struct OrdersHandler {
+    printavo: Printavo,
 }
 
-impl<'p> OrdersHandler<'p> {
-    pub(crate) fn new(printavo: &'p Printavo) -> Self {
-        Self { printavo }
+impl OrdersHandler {
+    pub(crate) fn new(printavo: &Printavo) -> Self {
+        Self {
+            printavo: printavo.clone(),
+        }
     }
 
-    pub fn list(&self) -> ListOrdersBuilder<'_, '_> {
-        ListOrdersBuilder::new(self)
+    pub fn list(&self) -> ListOrdersBuilder {
+        ListOrdersBuilder::new(self.clone())
     }
 
-    pub fn search(&self) -> SearchOrdersBuilder<'_, '_> {
-        SearchOrdersBuilder::new(self)
+    pub fn search(&self) -> SearchOrdersBuilder {
+        SearchOrdersBuilder::new(self.clone())
     }
 
     pub fn add_payment(
@@ -32,15 +37,23 @@ impl<'p> OrdersHandler<'p> {
         id: u32,
         amount: f32,
         formatted_transaction_date: impl Into<String>,
-    ) -> AddPaymentToOrderBuilder<'_, '_> {
-        AddPaymentToOrderBuilder::new(self, id, amount, formatted_transaction_date.into())
+    ) -> AddPaymentToOrderBuilder {
+        AddPaymentToOrderBuilder::new(self.clone(), id, amount, formatted_transaction_date.into())
+    }
+
+    pub fn refund(&self, order_id: u32, payment_id: u32, amount: f32) -> RefundPaymentBuilder {
+        RefundPaymentBuilder::new(self.clone(), order_id, payment_id, amount)
+    }
+
+    pub fn get(&self, id: u32) -> OrderDetailsBuilder {
+        OrderDetailsBuilder::new(self.clone(), id)
     }
 }
 
 #[derive(serde::Serialize)]
-pub struct ListOrdersBuilder<'p, 'b> {
+pub struct ListOrdersBuilder {
     #[serde(skip)]
-    handler: &'b OrdersHandler<'p>,
+    handler: OrdersHandler,
     #[serde(skip_serializing_if = "Option::is_none")]
     page: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -57,8 +70,8 @@ pub struct ListOrdersBuilder<'p, 'b> {
     in_production_before: Option<time::OffsetDateTime>,
 }
 
-impl<'p, 'b> ListOrdersBuilder<'p, 'b> {
-    pub(crate) fn new(handler: &'b OrdersHandler<'p>) -> Self {
+impl ListOrdersBuilder {
+    pub(crate) fn new(handler: OrdersHandler) -> Self {
         Self {
             handler,
             page: None,
@@ -107,15 +120,45 @@ impl<'p, 'b> ListOrdersBuilder<'p, 'b> {
     }
 
     pub async fn send(self) -> crate::Result<crate::Page<Order>> {
+        self.send_ref().await
+    }
+
+    async fn send_ref(&self) -> crate::Result<crate::Page<Order>> {
         let url = format!("api/{}/orders", self.handler.printavo.version);
-        self.handler.printavo.get(url, Some(&self)).await
+        self.handler.printavo.get(url, Some(self)).await
+    }
+
+    /// Stream every [`Order`] matching this query, automatically walking pages
+    /// as they're exhausted. Streaming starts at the page set with
+    /// [`page`](Self::page) (default: 1); earlier pages are not fetched.
+    ///
+    /// This issues one request per page, lazily, so large accounts aren't
+    /// buffered into memory all at once.
+    pub fn stream(mut self) -> impl futures::Stream<Item = crate::Result<Order>> {
+        async_stream::try_stream! {
+            let mut page = self.page.unwrap_or(1);
+            loop {
+                self.page = Some(page);
+                let result = self.send_ref().await?;
+                let exhausted = result.data.is_empty() || page >= result.meta.total_pages;
+
+                for order in result.data {
+                    yield order;
+                }
+
+                if exhausted {
+                    break;
+                }
+                page += 1;
+            }
+        }
     }
 }
 
 #[derive(serde::Serialize)]
-pub struct SearchOrdersBuilder<'p, 'b> {
+pub struct SearchOrdersBuilder {
     #[serde(skip)]
-    handler: &'b OrdersHandler<'p>,
+    handler: OrdersHandler,
     #[serde(skip_serializing_if = "Option::is_none")]
     page: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -124,8 +167,8 @@ pub struct SearchOrdersBuilder<'p, 'b> {
     query: Option<String>,
 }
 
-impl<'p, 'b> SearchOrdersBuilder<'p, 'b> {
-    pub(crate) fn new(handler: &'b OrdersHandler<'p>) -> Self {
+impl SearchOrdersBuilder {
+    pub(crate) fn new(handler: OrdersHandler) -> Self {
         Self {
             handler,
             page: None,
@@ -150,18 +193,51 @@ impl<'p, 'b> SearchOrdersBuilder<'p, 'b> {
     }
 
     pub async fn send(self) -> crate::Result<crate::Page<Order>> {
+        self.send_ref().await
+    }
+
+    async fn send_ref(&self) -> crate::Result<crate::Page<Order>> {
         let url = format!("api/{}/orders/search", self.handler.printavo.version);
-        self.handler.printavo.get(url, Some(&self)).await
+        self.handler.printavo.get(url, Some(self)).await
+    }
+
+    /// Stream every [`Order`] matching this query, automatically walking pages
+    /// as they're exhausted. Streaming starts at the page set with
+    /// [`page`](Self::page) (default: 1); earlier pages are not fetched.
+    ///
+    /// This issues one request per page, lazily, so large accounts aren't
+    /// buffered into memory all at once.
+    pub fn stream(mut self) -> impl futures::Stream<Item = crate::Result<Order>> {
+        async_stream::try_stream! {
+            let mut page = self.page.unwrap_or(1);
+            loop {
+                self.page = Some(page);
+                let result = self.send_ref().await?;
+                let exhausted = result.data.is_empty() || page >= result.meta.total_pages;
+
+                for order in result.data {
+                    yield order;
+                }
+
+                if exhausted {
+                    break;
+                }
+                page += 1;
+            }
+        }
     }
 }
 
 #[derive(Debug, serde::Deserialize)]
 pub struct Payment {
+    #[serde(deserialize_with = "crate::deserialize::deserialize_u32")]
     pub id: u32,
+    #[serde(deserialize_with = "crate::deserialize::deserialize_u32")]
     pub order_id: u32,
     #[serde(with = "time::serde::iso8601")]
     pub transaction_date: time::OffsetDateTime,
     pub name: Option<String>,
+    #[serde(deserialize_with = "crate::deserialize::deserialize_f32")]
     pub amount: f32,
     #[serde(with = "time::serde::iso8601")]
     pub created_at: time::OffsetDateTime,
@@ -170,17 +246,17 @@ pub struct Payment {
 }
 
 #[derive(serde::Serialize)]
-pub struct AddPaymentToOrderBuilder<'p, 'b> {
+pub struct AddPaymentToOrderBuilder {
     #[serde(skip)]
-    handler: &'b OrdersHandler<'p>,
+    handler: OrdersHandler,
     #[serde(skip)]
     id: u32,
     book: AddPaymentBook,
 }
 
-impl<'p, 'b> AddPaymentToOrderBuilder<'p, 'b> {
+impl AddPaymentToOrderBuilder {
     pub(crate) fn new(
-        handler: &'b OrdersHandler<'p>,
+        handler: OrdersHandler,
         id: u32,
         amount: f32,
         formatted_transaction_date: String,
@@ -239,3 +315,198 @@ impl AddPaymentBook {
         }
     }
 }
+
+#[derive(serde::Serialize)]
+pub struct RefundPaymentBuilder {
+    #[serde(skip)]
+    handler: OrdersHandler,
+    #[serde(skip)]
+    order_id: u32,
+    payment_id: u32,
+    amount: f32,
+}
+
+impl RefundPaymentBuilder {
+    pub(crate) fn new(handler: OrdersHandler, order_id: u32, payment_id: u32, amount: f32) -> Self {
+        Self {
+            handler,
+            order_id,
+            payment_id,
+            amount,
+        }
+    }
+
+    /// Override the refund amount, e.g. to issue a partial refund.
+    pub fn amount(mut self, amount: impl Into<f32>) -> Self {
+        self.amount = amount.into();
+        self
+    }
+
+    pub async fn send(self) -> crate::Result<Payment> {
+        let url = format!(
+            "api/{}/orders/{}/refund_payment",
+            self.handler.printavo.version, self.order_id
+        );
+        self.handler.printavo.post(url, Some(&self)).await
+    }
+}
+
+/// A customer associated with an [`OrderDetails`].
+#[derive(Debug, serde::Deserialize)]
+pub struct Customer {
+    #[serde(deserialize_with = "crate::deserialize::deserialize_u32")]
+    pub id: u32,
+    pub company: Option<String>,
+    pub email: Option<String>,
+}
+
+/// A single line item on an [`OrderDetails`].
+#[derive(Debug, serde::Deserialize)]
+pub struct LineItem {
+    #[serde(deserialize_with = "crate::deserialize::deserialize_u32")]
+    pub id: u32,
+    pub description: Option<String>,
+    pub quantity: u32,
+    #[serde(deserialize_with = "crate::deserialize::deserialize_f32")]
+    pub price: f32,
+}
+
+/// The full detail view of an order, including line items, customer, due
+/// date, and payment history.
+///
+/// Returned by [`OrdersHandler::get`]. The plain [`Order`] returned by
+/// `list`/`search` is intentionally thin; fetch this when reconciling
+/// balances or displaying an order in full.
+#[derive(Debug, serde::Deserialize)]
+pub struct OrderDetails {
+    #[serde(deserialize_with = "crate::deserialize::deserialize_u32")]
+    pub id: u32,
+    #[serde(deserialize_with = "crate::deserialize::deserialize_f32")]
+    pub order_total: f32,
+    pub customer: Customer,
+    #[serde(with = "time::serde::iso8601::option")]
+    pub customer_due_at: Option<time::OffsetDateTime>,
+    pub line_items: Vec<LineItem>,
+    pub payments: Vec<Payment>,
+}
+
+pub struct OrderDetailsBuilder {
+    handler: OrdersHandler,
+    id: u32,
+}
+
+impl OrderDetailsBuilder {
+    pub(crate) fn new(handler: OrdersHandler, id: u32) -> Self {
+        Self { handler, id }
+    }
+
+    pub async fn send(self) -> crate::Result<OrderDetails> {
+        let url = format!("api/{}/orders/{}", self.handler.printavo.version, self.id);
+        self.handler.printavo.get(url, None::<&()>).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+
+    use crate::{test_support, Printavo};
+
+    #[tokio::test]
+    async fn stream_walks_all_pages_and_stops_when_exhausted() {
+        let page1 = test_support::json_response(
+            200,
+            "OK",
+            r#"{"meta":{"page":1,"per_page":2,"total_count":3,"total_pages":2},"data":[{"id":1,"order_total":10.0},{"id":2,"order_total":20.0}]}"#,
+        );
+        let page2 = test_support::json_response(
+            200,
+            "OK",
+            r#"{"meta":{"page":2,"per_page":2,"total_count":3,"total_pages":2},"data":[{"id":3,"order_total":30.0}]}"#,
+        );
+
+        let (base_url, _server) = test_support::spawn_responder(vec![page1, page2]).await;
+        let printavo = Printavo::builder().base_url(base_url).unwrap().build().unwrap();
+
+        let ids: Vec<u32> = printavo
+            .orders()
+            .list()
+            .stream()
+            .map(|order| order.unwrap().id)
+            .collect()
+            .await;
+
+        assert_eq!(ids, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn stream_yields_an_error_instead_of_panicking_on_a_bad_page() {
+        let page1 = test_support::json_response(
+            200,
+            "OK",
+            r#"{"meta":{"page":1,"per_page":1,"total_count":2,"total_pages":2},"data":[{"id":1,"order_total":10.0}]}"#,
+        );
+        let page2 = test_support::text_response(500, "Internal Server Error", "boom");
+
+        let (base_url, _server) = test_support::spawn_responder(vec![page1, page2]).await;
+        let printavo = Printavo::builder().base_url(base_url).unwrap().build().unwrap();
+
+        let results: Vec<_> = printavo.orders().list().stream().collect().await;
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap().id, 1);
+        assert!(results[1].is_err());
+    }
+
+    #[tokio::test]
+    async fn refund_posts_and_parses_payment() {
+        let body = r#"{
+            "id": 42,
+            "order_id": 7,
+            "transaction_date": "2024-01-01T00:00:00.000000000Z",
+            "name": null,
+            "amount": -25.0,
+            "created_at": "2024-01-01T00:00:00.000000000Z",
+            "updated_at": "2024-01-01T00:00:00.000000000Z"
+        }"#;
+        let (base_url, _server) =
+            test_support::spawn_responder(vec![test_support::json_response(200, "OK", body)]).await;
+        let printavo = Printavo::builder().base_url(base_url).unwrap().build().unwrap();
+
+        let payment = printavo
+            .orders()
+            .refund(7, 42, 25.0)
+            .amount(-25.0)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(payment.id, 42);
+        assert_eq!(payment.order_id, 7);
+        assert_eq!(payment.amount, -25.0);
+    }
+
+    #[tokio::test]
+    async fn get_order_details_parses_nested_fields() {
+        let body = r#"{
+            "id": 99,
+            "order_total": 150.5,
+            "customer": {"id": 5, "company": "Acme", "email": "a@example.com"},
+            "customer_due_at": "2024-02-01T00:00:00.000000000Z",
+            "line_items": [{"id": 1, "description": "Shirt", "quantity": 2, "price": 10.0}],
+            "payments": []
+        }"#;
+        let (base_url, _server) =
+            test_support::spawn_responder(vec![test_support::json_response(200, "OK", body)]).await;
+        let printavo = Printavo::builder().base_url(base_url).unwrap().build().unwrap();
+
+        let details = printavo.orders().get(99).send().await.unwrap();
+
+        assert_eq!(details.id, 99);
+        assert_eq!(details.order_total, 150.5);
+        assert_eq!(details.customer.company.as_deref(), Some("Acme"));
+        assert_eq!(details.line_items.len(), 1);
+        assert_eq!(details.line_items[0].price, 10.0);
+        assert!(details.payments.is_empty());
+    }
+}
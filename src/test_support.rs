@@ -0,0 +1,78 @@
+//! Test-only helpers for exercising the client against a throwaway local TCP
+//! listener instead of mocking at the `reqwest` layer.
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Spawn a listener that serves `responses` in order, one per inbound
+/// connection, then shuts down. Returns the listener's base URL.
+pub(crate) async fn spawn_responder(responses: Vec<String>) -> (String, tokio::task::JoinHandle<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}/", listener.local_addr().unwrap());
+
+    let handle = tokio::spawn(async move {
+        for response in responses {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            read_request(&mut stream).await;
+            stream.write_all(response.as_bytes()).await.unwrap();
+            stream.flush().await.unwrap();
+        }
+    });
+
+    (base_url, handle)
+}
+
+/// Read a full HTTP request (headers + body, per `Content-Length`) so the
+/// connection can be closed afterwards without resetting it.
+async fn read_request(stream: &mut tokio::net::TcpStream) {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+
+    loop {
+        let n = stream.read(&mut chunk).await.unwrap();
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+
+        let Some(header_end) = buf.windows(4).position(|w| w == b"\r\n\r\n") else {
+            continue;
+        };
+
+        let content_length = String::from_utf8_lossy(&buf[..header_end])
+            .lines()
+            .find_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                name.eq_ignore_ascii_case("content-length")
+                    .then(|| value.trim().parse::<usize>().ok())
+                    .flatten()
+            })
+            .unwrap_or(0);
+
+        if buf.len() >= header_end + 4 + content_length {
+            break;
+        }
+    }
+}
+
+/// A minimal `HTTP/1.1` response with a JSON body.
+pub(crate) fn json_response(status: u16, reason: &str, body: &str) -> String {
+    response(status, reason, "application/json", body)
+}
+
+/// A minimal `HTTP/1.1` response with a plain-text body.
+pub(crate) fn text_response(status: u16, reason: &str, body: &str) -> String {
+    response(status, reason, "text/plain", body)
+}
+
+fn response(status: u16, reason: &str, content_type: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status} {reason}\r\n\
+         Content-Type: {content_type}\r\n\
+         Content-Length: {len}\r\n\
+         Connection: close\r\n\
+         \r\n\
+         {body}",
+        len = body.len(),
+    )
+}
@@ -0,0 +1,116 @@
+//! Inbound webhook parsing and signature verification.
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+
+use crate::error::Error;
+use crate::orders::{Order, Payment};
+use crate::Printavo;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const SIGNATURE_HEADER: &str = "x-printavo-signature";
+
+/// A Printavo webhook event, decoded from an inbound HTTP payload.
+///
+/// See [`WebhooksHandler::parse`].
+#[derive(Debug)]
+pub enum WebhookEvent {
+    PaymentCreated(Payment),
+    OrderUpdated(Order),
+    /// An event type this version of the crate doesn't know how to decode
+    /// yet; the raw payload is kept so callers can still act on it.
+    Unknown {
+        event_type: String,
+        payload: serde_json::Value,
+    },
+}
+
+/// Handler for Printavo's webhook payloads.
+///
+/// Created with [`Printavo::webhooks`].
+pub struct WebhooksHandler<'p> {
+    printavo: &'p Printavo,
+}
+
+impl<'p> WebhooksHandler<'p> {
+    pub(crate) fn new(printavo: &'p Printavo) -> Self {
+        Self { printavo }
+    }
+
+    /// Parse an inbound webhook request into a [`WebhookEvent`].
+    ///
+    /// If a webhook signing secret was configured via
+    /// [`PrintavoBuilder::webhook_signing_secret`](crate::PrintavoBuilder::webhook_signing_secret),
+    /// the `x-printavo-signature` header is verified against `body` first,
+    /// returning [`Error::Api`] on mismatch.
+    pub fn parse(&self, headers: &reqwest::header::HeaderMap, body: &[u8]) -> crate::Result<WebhookEvent> {
+        if let Some(secret) = &self.printavo.webhook_signing_secret {
+            verify_signature(secret, headers, body)?;
+        }
+
+        let de = &mut serde_json::Deserializer::from_slice(body);
+        let envelope: WebhookEnvelope =
+            serde_path_to_error::deserialize(de).map_err(|source| Error::Json { source })?;
+
+        let event = match envelope.event_type.as_str() {
+            "payment_created" => WebhookEvent::PaymentCreated(parse_payload(envelope.data)?),
+            "order_updated" => WebhookEvent::OrderUpdated(parse_payload(envelope.data)?),
+            _ => WebhookEvent::Unknown {
+                event_type: envelope.event_type,
+                payload: envelope.data,
+            },
+        };
+
+        Ok(event)
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct WebhookEnvelope {
+    event_type: String,
+    data: serde_json::Value,
+}
+
+fn parse_payload<T: serde::de::DeserializeOwned>(payload: serde_json::Value) -> crate::Result<T> {
+    serde_path_to_error::deserialize(payload).map_err(|source| Error::Json { source })
+}
+
+fn verify_signature(
+    secret: &str,
+    headers: &reqwest::header::HeaderMap,
+    body: &[u8],
+) -> crate::Result<()> {
+    let signature = headers
+        .get(SIGNATURE_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .ok_or_else(missing_signature)?;
+
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts a key of any length");
+    mac.update(body);
+    let expected = hex::encode(mac.finalize().into_bytes());
+
+    if expected.as_bytes().ct_eq(signature.as_bytes()).into() {
+        Ok(())
+    } else {
+        Err(signature_mismatch())
+    }
+}
+
+fn missing_signature() -> Error {
+    Error::Api {
+        status: reqwest::StatusCode::UNAUTHORIZED,
+        message: format!("missing `{SIGNATURE_HEADER}` header"),
+        details: Vec::new(),
+    }
+}
+
+fn signature_mismatch() -> Error {
+    Error::Api {
+        status: reqwest::StatusCode::UNAUTHORIZED,
+        message: "webhook signature mismatch".to_string(),
+        details: Vec::new(),
+    }
+}